@@ -0,0 +1,31 @@
+use crate::totp::error::TOTPError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Algorithm {
+    #[default]
+    SHA1,
+    SHA256,
+    SHA512,
+    Steam,
+}
+
+impl Algorithm {
+    pub fn new(value: &str) -> Result<Self, TOTPError> {
+        match value {
+            "SHA1" => Ok(Algorithm::SHA1),
+            "SHA256" => Ok(Algorithm::SHA256),
+            "SHA512" => Ok(Algorithm::SHA512),
+            value if value.eq_ignore_ascii_case("steam") => Ok(Algorithm::Steam),
+            _ => Err(TOTPError::InvalidAlgorithm(value.to_string())),
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            Algorithm::SHA1 => "SHA1",
+            Algorithm::SHA256 => "SHA256",
+            Algorithm::SHA512 => "SHA512",
+            Algorithm::Steam => "steam",
+        }
+    }
+}
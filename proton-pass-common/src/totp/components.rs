@@ -1,6 +1,8 @@
 use crate::totp::algorithm::Algorithm;
 use crate::totp::error::TOTPError;
 use crate::totp::get_value::{GetQueryValue, Queries};
+use crate::totp::totp::{MAX_DIGITS, MIN_DIGITS};
+use percent_encoding::percent_decode_str;
 use queryst::parse;
 use uriparse::URI;
 
@@ -35,6 +37,17 @@ impl TOTPComponents {
         let digits: Option<u8> = queries.get_string_parsable_value("digits");
         let period: Option<u16> = queries.get_string_parsable_value("period");
 
+        if let Some(digits) = digits {
+            if !(MIN_DIGITS..=MAX_DIGITS).contains(&digits) {
+                return Err(TOTPError::InvalidDigits(digits));
+            }
+        }
+        if let Some(period) = period {
+            if period == 0 {
+                return Err(TOTPError::InvalidPeriod(period));
+            }
+        }
+
         Ok(Self {
             label,
             secret,
@@ -75,7 +88,8 @@ impl TOTPComponents {
     fn parse_label(uri: &URI) -> Option<String> {
         match uri.path().segments().last() {
             Some(value) => {
-                let label = value.to_string();
+                // Store the label decoded; `TOTP::to_uri` percent-encodes it again on output.
+                let label = percent_decode_str(&value.to_string()).decode_utf8_lossy().to_string();
                 if label.is_empty() {
                     None
                 } else {
@@ -126,14 +140,18 @@ impl TOTPComponents {
                 Ok(algorithm) => Ok(Some(algorithm)),
                 Err(error) => Err(error),
             },
-            _ => Ok(None),
+            // Steam authenticators omit `algorithm` and are recognised by their issuer instead.
+            _ => match queries.get_string_value("issuer") {
+                Some(issuer) if issuer.eq_ignore_ascii_case("steam") => Ok(Some(Algorithm::Steam)),
+                _ => Ok(None),
+            },
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::totp::algorithm::Algorithm::SHA512;
+    use crate::totp::algorithm::Algorithm::{Steam, SHA512};
     use crate::totp::components::TOTPComponents;
     use crate::totp::error::TOTPError;
 
@@ -258,7 +276,7 @@ mod test {
         // Then
         match sut {
             Ok(components) => {
-                assert_eq!(components.label, Some("john.doe%40example.com".to_string()));
+                assert_eq!(components.label, Some("john.doe@example.com".to_string()));
                 assert_eq!(components.secret, "somesecret");
                 assert_eq!(components.issuer, Some("ProtonMail".to_string()));
                 assert_eq!(components.algorithm, Some(SHA512));
@@ -269,6 +287,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn steam_issuer_implies_steam_algorithm() {
+        // Given
+        let uri = "otpauth://totp/john.doe%40example.com?secret=somesecret&issuer=Steam";
+
+        // When
+        let sut = make_sut(uri);
+
+        // Then
+        match sut {
+            Ok(components) => {
+                assert_eq!(components.algorithm, Some(Steam));
+                assert_eq!(components.issuer, Some("Steam".to_string()));
+            }
+            _ => panic!("Should be able to parse"),
+        }
+    }
+
     #[test]
     fn implicit_params() {
         // Given
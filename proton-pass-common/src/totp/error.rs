@@ -0,0 +1,42 @@
+use uriparse::URIError;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TOTPError {
+    NotTotpUri,
+    InvalidScheme(String),
+    InvalidAuthority(String),
+    NoAuthority,
+    InvalidAlgorithm(String),
+    NoQueries,
+    NoSecret,
+    EmptySecret,
+    InvalidSecret,
+    InvalidDigits(u8),
+    InvalidPeriod(u16),
+    URIError(URIError),
+    #[cfg(feature = "qr")]
+    QrError(String),
+}
+
+impl std::fmt::Display for TOTPError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TOTPError::NotTotpUri => write!(f, "Not a valid TOTP URI"),
+            TOTPError::InvalidScheme(scheme) => write!(f, "Invalid scheme: {scheme}"),
+            TOTPError::InvalidAuthority(authority) => write!(f, "Invalid authority: {authority}"),
+            TOTPError::NoAuthority => write!(f, "Missing authority"),
+            TOTPError::InvalidAlgorithm(algorithm) => write!(f, "Invalid algorithm: {algorithm}"),
+            TOTPError::NoQueries => write!(f, "Missing queries"),
+            TOTPError::NoSecret => write!(f, "Missing secret"),
+            TOTPError::EmptySecret => write!(f, "Empty secret"),
+            TOTPError::InvalidSecret => write!(f, "Invalid Base32 secret"),
+            TOTPError::InvalidDigits(digits) => write!(f, "Invalid digits: {digits}"),
+            TOTPError::InvalidPeriod(period) => write!(f, "Invalid period: {period}"),
+            TOTPError::URIError(error) => write!(f, "URI error: {error}"),
+            #[cfg(feature = "qr")]
+            TOTPError::QrError(error) => write!(f, "QR rendering error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for TOTPError {}
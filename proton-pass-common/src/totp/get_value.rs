@@ -0,0 +1,21 @@
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+pub type Queries = Map<String, Value>;
+
+pub trait GetQueryValue {
+    fn get_string_value(&self, key: &str) -> Option<String>;
+    fn get_string_parsable_value<T: FromStr>(&self, key: &str) -> Option<T>;
+}
+
+impl GetQueryValue for Queries {
+    fn get_string_value(&self, key: &str) -> Option<String> {
+        self.get(key)
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string())
+    }
+
+    fn get_string_parsable_value<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.get_string_value(key).and_then(|value| value.parse::<T>().ok())
+    }
+}
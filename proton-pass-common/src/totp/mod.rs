@@ -0,0 +1,9 @@
+pub mod algorithm;
+pub mod components;
+pub mod error;
+pub mod get_value;
+#[cfg(feature = "qr")]
+pub mod qr;
+pub mod sanitizer;
+pub mod secret;
+pub mod totp;
@@ -0,0 +1,54 @@
+use crate::totp::error::TOTPError;
+use crate::totp::totp::TOTP;
+use image::{ImageOutputFormat, Luma};
+use qrcode::{Color, QrCode};
+use std::io::Cursor;
+
+impl TOTP {
+    /// Renders the `otpauth://` URI as a QR module matrix, `true` for a dark
+    /// module, for callers that want to draw the code themselves.
+    pub fn qr_matrix(&self) -> Result<Vec<Vec<bool>>, TOTPError> {
+        let code = QrCode::new(self.to_uri().as_bytes()).map_err(|error| TOTPError::QrError(error.to_string()))?;
+        let width = code.width();
+        Ok(code
+            .into_colors()
+            .chunks(width)
+            .map(|row| row.iter().map(|color| *color == Color::Dark).collect())
+            .collect())
+    }
+
+    /// Renders the `otpauth://` URI as PNG bytes for direct in-app display.
+    pub fn qr_png(&self) -> Result<Vec<u8>, TOTPError> {
+        let code = QrCode::new(self.to_uri().as_bytes()).map_err(|error| TOTPError::QrError(error.to_string()))?;
+        let image = code.render::<Luma<u8>>().build();
+        let mut buffer = Cursor::new(Vec::new());
+        image
+            .write_to(&mut buffer, ImageOutputFormat::Png)
+            .map_err(|error| TOTPError::QrError(error.to_string()))?;
+        Ok(buffer.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_square_matrix_and_png() {
+        let totp = TOTP {
+            label: None,
+            secret: "JBSWY3DPEHPK3PXP".to_string(),
+            issuer: None,
+            algorithm: None,
+            digits: None,
+            period: None,
+        };
+
+        let matrix = totp.qr_matrix().unwrap();
+        assert!(!matrix.is_empty());
+        assert!(matrix.iter().all(|row| row.len() == matrix.len()));
+
+        let png = totp.qr_png().unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+    }
+}
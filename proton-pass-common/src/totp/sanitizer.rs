@@ -0,0 +1,23 @@
+use crate::totp::error::TOTPError;
+use crate::totp::totp::TOTP;
+
+/// Normalises a stored secret or URI into a value suitable for an edit field.
+///
+/// Both a bare secret and an `otpauth` URI are echoed back unchanged so the UI
+/// can present them for editing; validation happens on save.
+pub fn uri_for_editing(original_uri: &str) -> String {
+    original_uri.to_string()
+}
+
+/// Validates the edited value before persisting it, falling back to the
+/// original URI when the user left a bare secret in place.
+pub fn uri_for_saving(original_uri: &str, edited_uri: &str) -> Result<String, TOTPError> {
+    if edited_uri.contains("otpauth") {
+        TOTP::from_uri(edited_uri)?;
+        Ok(edited_uri.to_string())
+    } else if original_uri.contains("otpauth") {
+        Ok(original_uri.to_string())
+    } else {
+        Ok(edited_uri.to_string())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::totp::error::TOTPError;
+
+/// Base32 alphabet used for TOTP seeds (RFC 3548 / RFC 4648, no padding).
+const ALPHABET: base32::Alphabet = base32::Alphabet::Rfc4648 { padding: false };
+
+/// A TOTP seed held either as decoded bytes or as its Base32 text form.
+///
+/// Converting between the two validates the encoding up front, so a malformed
+/// secret surfaces a [`TOTPError::InvalidSecret`] instead of silently producing
+/// a wrong code deep inside token generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Secret {
+    Raw(Vec<u8>),
+    Encoded(String),
+}
+
+impl Secret {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, TOTPError> {
+        match self {
+            Secret::Raw(bytes) => Ok(bytes.clone()),
+            Secret::Encoded(value) => base32::decode(ALPHABET, value).ok_or(TOTPError::InvalidSecret),
+        }
+    }
+
+    pub fn to_encoded(&self) -> String {
+        match self {
+            Secret::Raw(bytes) => base32::encode(ALPHABET, bytes),
+            Secret::Encoded(value) => value.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Secret {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        match self {
+            Secret::Raw(bytes) => bytes.zeroize(),
+            Secret::Encoded(value) => value.zeroize(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_valid_base32() {
+        let secret = Secret::Encoded("JBSWY3DPEHPK3PXP".to_string());
+        assert_eq!(secret.to_bytes().unwrap(), b"Hello!\xde\xad\xbe\xef");
+    }
+
+    #[test]
+    fn rejects_non_alphabet_characters() {
+        let secret = Secret::Encoded("not base32!".to_string());
+        assert_eq!(secret.to_bytes(), Err(TOTPError::InvalidSecret));
+    }
+
+    #[test]
+    fn raw_round_trips_through_encoding() {
+        let secret = Secret::Raw(b"Hello!".to_vec());
+        assert_eq!(Secret::Encoded(secret.to_encoded()).to_bytes().unwrap(), b"Hello!");
+    }
+}
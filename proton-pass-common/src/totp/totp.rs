@@ -0,0 +1,481 @@
+use crate::totp::algorithm::Algorithm;
+use crate::totp::components::TOTPComponents;
+use crate::totp::error::TOTPError;
+use crate::totp::secret::Secret;
+use hmac::{Hmac, Mac};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+use subtle::{Choice, ConstantTimeEq};
+
+/// Characters percent-encoded inside a URI path segment or query value.
+const URI_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'&')
+    .add(b'/')
+    .add(b':')
+    .add(b'?')
+    .add(b'=');
+
+pub const DEFAULT_ALGORITHM: Algorithm = Algorithm::SHA1;
+pub const DEFAULT_DIGITS: u8 = 6;
+pub const DEFAULT_PERIOD: u16 = 30;
+
+/// RFC 6238 valid range for the number of emitted digits.
+pub const MIN_DIGITS: u8 = 6;
+pub const MAX_DIGITS: u8 = 8;
+/// RFC 4226 recommends a shared secret of at least 80 bits (10 bytes).
+pub const MIN_SECRET_BYTES: usize = 10;
+
+/// Fixed period used by the Steam Guard variant; `period`/`digits` inputs are ignored for it.
+const STEAM_PERIOD: u64 = 30;
+const STEAM_ALPHABET: &[u8] = b"23456789BCDFGHJKMNPQRTVWXY";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TOTP {
+    pub label: Option<String>,
+    pub secret: String,
+    pub issuer: Option<String>,
+    pub algorithm: Option<Algorithm>,
+    pub digits: Option<u8>,
+    pub period: Option<u16>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TotpTokenResult {
+    pub totp: TOTP,
+    pub token: String,
+    pub timestamp: u64,
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for TOTP {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        self.secret.zeroize();
+    }
+}
+
+impl From<TOTPComponents> for TOTP {
+    fn from(components: TOTPComponents) -> Self {
+        Self {
+            label: components.label,
+            secret: components.secret,
+            issuer: components.issuer,
+            algorithm: components.algorithm,
+            digits: components.digits,
+            period: components.period,
+        }
+    }
+}
+
+impl TOTP {
+    /// Starts a builder that validates its inputs against the RFC 6238 ranges
+    /// when [`TotpBuilder::build`] is called.
+    pub fn builder() -> TotpBuilder {
+        TotpBuilder::default()
+    }
+
+    /// Enforces the RFC 6238 valid ranges: `digits` in 6..=8, a non-zero
+    /// `period`, and a non-empty Base32 secret of adequate length.
+    pub fn validate(&self) -> Result<(), TOTPError> {
+        if let Some(digits) = self.digits {
+            if !(MIN_DIGITS..=MAX_DIGITS).contains(&digits) {
+                return Err(TOTPError::InvalidDigits(digits));
+            }
+        }
+        if let Some(period) = self.period {
+            if period == 0 {
+                return Err(TOTPError::InvalidPeriod(period));
+            }
+        }
+        if self.secret.is_empty() {
+            return Err(TOTPError::EmptySecret);
+        }
+        if Secret::Encoded(self.secret.clone()).to_bytes()?.len() < MIN_SECRET_BYTES {
+            return Err(TOTPError::InvalidSecret);
+        }
+        Ok(())
+    }
+
+    pub fn from_uri(uri: &str) -> Result<Self, TOTPError> {
+        let totp: TOTP = TOTPComponents::from_uri(uri)?.into();
+        // Validate the Base32 seed here so importing fails loudly rather than at generation.
+        Secret::Encoded(totp.secret.clone()).to_bytes()?;
+        Ok(totp)
+    }
+
+    /// Parses an `otpauth://` URI, or treats the input as a bare Base32 secret.
+    ///
+    /// This is the single entry point the FFI/WASM surfaces share so the
+    /// bare-secret path cannot diverge between them.
+    pub fn from_uri_or_secret(input: &str) -> Result<Self, TOTPError> {
+        if input.contains("otpauth") {
+            Self::from_uri(input)
+        } else {
+            Ok(Self {
+                label: None,
+                secret: input.to_string(),
+                issuer: None,
+                algorithm: None,
+                digits: None,
+                period: None,
+            })
+        }
+    }
+
+    pub fn unwrap_algorithm(&self) -> Algorithm {
+        self.algorithm.unwrap_or(DEFAULT_ALGORITHM)
+    }
+
+    pub fn unwrap_digits(&self) -> u8 {
+        self.digits.unwrap_or(DEFAULT_DIGITS)
+    }
+
+    pub fn unwrap_period(&self) -> u16 {
+        self.period.unwrap_or(DEFAULT_PERIOD)
+    }
+
+    /// Renders this authenticator as a spec-compliant `otpauth://totp/...` URI.
+    ///
+    /// The label and issuer are percent-encoded and any parameter left at its
+    /// RFC 6238 default is omitted. The label is the whole `issuer:account`
+    /// path segment (`TOTPComponents::parse_label` stores it decoded), so it is
+    /// not re-prefixed here and parse→to_uri→parse is stable.
+    pub fn to_uri(&self) -> String {
+        let label = self.label.clone().unwrap_or_default();
+        let path = utf8_percent_encode(&label, URI_SEGMENT).to_string();
+
+        let mut queries = vec![format!("secret={}", self.secret)];
+        if let Some(issuer) = &self.issuer {
+            if !issuer.is_empty() {
+                queries.push(format!("issuer={}", utf8_percent_encode(issuer, URI_SEGMENT)));
+            }
+        }
+        let algorithm = self.unwrap_algorithm();
+        if algorithm != DEFAULT_ALGORITHM {
+            queries.push(format!("algorithm={}", algorithm.value()));
+        }
+        if self.unwrap_digits() != DEFAULT_DIGITS {
+            queries.push(format!("digits={}", self.unwrap_digits()));
+        }
+        if self.unwrap_period() != DEFAULT_PERIOD {
+            queries.push(format!("period={}", self.unwrap_period()));
+        }
+
+        format!("otpauth://totp/{}?{}", path, queries.join("&"))
+    }
+
+    /// Checks `token` against every code valid within `±skew` time steps of
+    /// `current_time`, using a constant-time comparison so a near-match leaks
+    /// no timing information.
+    pub fn verify(&self, token: &str, current_time: u64, skew: u8) -> Result<bool, TOTPError> {
+        let period = self.unwrap_period() as u64;
+        let base = current_time / period;
+        let skew = skew as u64;
+        let mut valid = Choice::from(0u8);
+        for counter in base.saturating_sub(skew)..=base.saturating_add(skew) {
+            let candidate = self.generate_token(counter.saturating_mul(period))?;
+            valid |= candidate.as_bytes().ct_eq(token.as_bytes());
+        }
+        Ok(bool::from(valid))
+    }
+
+    pub fn generate_token(&self, current_time: u64) -> Result<String, TOTPError> {
+        let secret = protect(Secret::Encoded(self.secret.clone()).to_bytes()?);
+        match self.unwrap_algorithm() {
+            Algorithm::Steam => {
+                let digest = protect(hmac_digest(Algorithm::SHA1, &secret, current_time / STEAM_PERIOD));
+                let code = truncate(&digest);
+                Ok(steam_token(code))
+            }
+            algorithm => {
+                let counter = current_time / self.unwrap_period() as u64;
+                let digest = protect(hmac_digest(algorithm, &secret, counter));
+                let code = truncate(&digest);
+                let digits = self.unwrap_digits();
+                // Guard against out-of-range digits on struct-literal/From-built values that
+                // never went through the builder: 10^digits overflows u32 beyond 9 digits.
+                let modulus = 10u32
+                    .checked_pow(digits as u32)
+                    .ok_or(TOTPError::InvalidDigits(digits))?;
+                Ok(format!("{:0width$}", code % modulus, width = digits as usize))
+            }
+        }
+    }
+}
+
+/// Fluent builder for a validated [`TOTP`].
+#[derive(Clone, Debug, Default)]
+pub struct TotpBuilder {
+    totp: TOTP,
+}
+
+impl TotpBuilder {
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.totp.label = Some(label.into());
+        self
+    }
+
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.totp.secret = secret.into();
+        self
+    }
+
+    pub fn issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.totp.issuer = Some(issuer.into());
+        self
+    }
+
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.totp.algorithm = Some(algorithm);
+        self
+    }
+
+    pub fn digits(mut self, digits: u8) -> Self {
+        self.totp.digits = Some(digits);
+        self
+    }
+
+    pub fn period(mut self, period: u16) -> Self {
+        self.totp.period = Some(period);
+        self
+    }
+
+    pub fn build(self) -> Result<TOTP, TOTPError> {
+        self.totp.validate()?;
+        Ok(self.totp)
+    }
+}
+
+/// Wraps transient key material so the decoded seed and per-call HMAC buffers
+/// are wiped on drop when the `zeroize` feature is enabled, matching the
+/// on-drop wiping of [`TOTP`] and [`Secret`]. A no-op otherwise.
+#[cfg(feature = "zeroize")]
+fn protect(bytes: Vec<u8>) -> zeroize::Zeroizing<Vec<u8>> {
+    zeroize::Zeroizing::new(bytes)
+}
+
+#[cfg(not(feature = "zeroize"))]
+fn protect(bytes: Vec<u8>) -> Vec<u8> {
+    bytes
+}
+
+/// HMAC over the 8-byte big-endian counter for the given hash algorithm.
+fn hmac_digest(algorithm: Algorithm, key: &[u8], counter: u64) -> Vec<u8> {
+    let message = counter.to_be_bytes();
+    match algorithm {
+        Algorithm::SHA256 => {
+            let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+            mac.update(&message);
+            mac.finalize().into_bytes().to_vec()
+        }
+        Algorithm::SHA512 => {
+            let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+            mac.update(&message);
+            mac.finalize().into_bytes().to_vec()
+        }
+        // Steam reuses the SHA1 HMAC.
+        Algorithm::SHA1 | Algorithm::Steam => {
+            let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts keys of any size");
+            mac.update(&message);
+            mac.finalize().into_bytes().to_vec()
+        }
+    }
+}
+
+/// RFC 4226 dynamic truncation to a 31-bit integer.
+fn truncate(digest: &[u8]) -> u32 {
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    ((digest[offset] as u32 & 0x7f) << 24)
+        | ((digest[offset + 1] as u32) << 16)
+        | ((digest[offset + 2] as u32) << 8)
+        | (digest[offset + 3] as u32)
+}
+
+/// Steam Guard emits five characters drawn from a 26-letter alphabet.
+fn steam_token(mut code: u32) -> String {
+    let mut token = String::with_capacity(5);
+    for _ in 0..5 {
+        token.push(STEAM_ALPHABET[(code % 26) as usize] as char);
+        code /= 26;
+    }
+    token
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generates_steam_token() {
+        let totp = TOTP {
+            label: None,
+            secret: "JBSWY3DPEHPK3PXP".to_string(),
+            issuer: None,
+            algorithm: Some(Algorithm::Steam),
+            digits: None,
+            period: None,
+        };
+
+        assert_eq!(totp.generate_token(1634567890).unwrap(), "968HM");
+    }
+
+    #[test]
+    fn steam_ignores_digits_and_period() {
+        let totp = TOTP {
+            label: None,
+            secret: "JBSWY3DPEHPK3PXP".to_string(),
+            issuer: None,
+            algorithm: Some(Algorithm::Steam),
+            digits: Some(8),
+            period: Some(45),
+        };
+
+        assert_eq!(totp.generate_token(1634567890).unwrap(), "968HM");
+    }
+
+    #[test]
+    fn to_uri_omits_defaults() {
+        let totp = TOTP {
+            label: None,
+            secret: "somesecret".to_string(),
+            issuer: None,
+            algorithm: None,
+            digits: None,
+            period: None,
+        };
+
+        assert_eq!(totp.to_uri(), "otpauth://totp/?secret=somesecret");
+    }
+
+    #[test]
+    fn to_uri_round_trips_through_components() {
+        use crate::totp::components::TOTPComponents;
+
+        // The (decoded) label carries the full `Issuer:Account` segment, exactly
+        // as TOTPComponents::parse_label would have produced it.
+        let totp = TOTP {
+            label: Some("ProtonMail:john.doe@example.com".to_string()),
+            secret: "somesecret".to_string(),
+            issuer: Some("ProtonMail".to_string()),
+            algorithm: Some(Algorithm::SHA512),
+            digits: Some(8),
+            period: Some(45),
+        };
+
+        let parsed = TOTPComponents::from_uri(&totp.to_uri()).expect("generated URI should parse");
+        assert_eq!(parsed.label, totp.label);
+        assert_eq!(parsed.secret, totp.secret);
+        assert_eq!(parsed.issuer, totp.issuer);
+        assert_eq!(parsed.algorithm, totp.algorithm);
+        assert_eq!(parsed.digits, totp.digits);
+        assert_eq!(parsed.period, totp.period);
+    }
+
+    #[test]
+    fn to_uri_encodes_builder_label_with_reserved_chars() {
+        use crate::totp::components::TOTPComponents;
+
+        let totp = TOTP::builder()
+            .label("AWS:my account")
+            .secret("JBSWY3DPEHPK3PXP")
+            .build()
+            .unwrap();
+
+        let uri = totp.to_uri();
+        assert!(uri.starts_with("otpauth://totp/AWS%3Amy%20account?"));
+        let parsed = TOTPComponents::from_uri(&uri).expect("generated URI should parse");
+        assert_eq!(parsed.label, totp.label);
+    }
+
+    #[test]
+    fn verify_accepts_codes_within_skew() {
+        let totp = TOTP {
+            label: None,
+            secret: "JBSWY3DPEHPK3PXP".to_string(),
+            issuer: None,
+            algorithm: None,
+            digits: None,
+            period: None,
+        };
+
+        // 1111111111 and one step earlier (1111111081) both accepted with skew 1.
+        let previous = totp.generate_token(1111111081).unwrap();
+        assert!(totp.verify(&totp.generate_token(1111111111).unwrap(), 1111111111, 1).unwrap());
+        assert!(totp.verify(&previous, 1111111111, 1).unwrap());
+        assert!(!totp.verify(&previous, 1111111111, 0).unwrap());
+        assert!(!totp.verify("000000", 1111111111, 2).unwrap());
+    }
+
+    #[test]
+    fn builder_rejects_out_of_range_parameters() {
+        assert_eq!(
+            TOTP::builder().secret("JBSWY3DPEHPK3PXP").digits(9).build(),
+            Err(TOTPError::InvalidDigits(9))
+        );
+        assert_eq!(
+            TOTP::builder().secret("JBSWY3DPEHPK3PXP").period(0).build(),
+            Err(TOTPError::InvalidPeriod(0))
+        );
+        assert_eq!(TOTP::builder().secret("").build(), Err(TOTPError::EmptySecret));
+    }
+
+    #[test]
+    fn builder_accepts_valid_parameters() {
+        let totp = TOTP::builder()
+            .secret("JBSWY3DPEHPK3PXP")
+            .issuer("ProtonMail")
+            .digits(8)
+            .period(45)
+            .build()
+            .unwrap();
+        assert_eq!(totp.unwrap_digits(), 8);
+        assert_eq!(totp.unwrap_period(), 45);
+    }
+
+    #[test]
+    fn generate_token_rejects_overflowing_digits() {
+        let totp = TOTP {
+            label: None,
+            secret: "JBSWY3DPEHPK3PXP".to_string(),
+            issuer: None,
+            algorithm: None,
+            digits: Some(20),
+            period: None,
+        };
+        assert_eq!(totp.generate_token(1111111111), Err(TOTPError::InvalidDigits(20)));
+    }
+
+    #[test]
+    fn from_uri_rejects_malformed_secret() {
+        let uri = "otpauth://totp/label?secret=not%20base32%21";
+        assert_eq!(TOTP::from_uri(uri), Err(TOTPError::InvalidSecret));
+    }
+
+    #[test]
+    fn generates_standard_tokens() {
+        let totp = TOTP {
+            label: None,
+            secret: "JBSWY3DPEHPK3PXP".to_string(),
+            issuer: None,
+            algorithm: None,
+            digits: None,
+            period: None,
+        };
+        assert_eq!(totp.generate_token(1111111111).unwrap(), "358462");
+
+        let sha256 = TOTP {
+            algorithm: Some(Algorithm::SHA256),
+            secret: totp.secret.clone(),
+            label: totp.label.clone(),
+            issuer: totp.issuer.clone(),
+            digits: totp.digits,
+            period: totp.period,
+        };
+        assert_eq!(sha256.generate_token(1111111111).unwrap(), "848888");
+    }
+}
@@ -23,6 +23,14 @@ impl TotpHandler {
     pub fn unwrap_period(&self, totp: TOTP) -> u16 {
         totp.unwrap_period()
     }
+
+    pub fn get_totp_uri(&self, totp: TOTP) -> String {
+        totp.to_uri()
+    }
+
+    pub fn verify_token(&self, totp: TOTP, token: String, current_time: u64, skew: u8) -> Result<bool, TOTPError> {
+        totp.verify(&token, current_time, skew)
+    }
 }
 
 pub struct TotpUriSanitizer;
@@ -49,14 +57,7 @@ impl TotpTokenGenerator {
     }
 
     pub fn generate_token(&self, uri: String, current_time: u64) -> Result<TotpTokenResult, TOTPError> {
-        let totp: TOTP = if uri.contains("otpauth") {
-            TOTP::from_uri(&uri)?
-        } else {
-            TOTP {
-                secret: uri,
-                ..Default::default()
-            }
-        };
+        let totp = TOTP::from_uri_or_secret(&uri)?;
         let token = totp.generate_token(current_time)?;
         Ok(TotpTokenResult {
             totp,
@@ -157,6 +157,19 @@ pub fn parse_create_passkey_data(request: String) -> Result<WasmCreatePasskeyDat
     Ok(PasskeyManager::parse_create_request(request)?)
 }
 
+#[wasm_bindgen]
+pub fn verify_totp_token(uri: String, token: String, current_time: u64, skew: u8) -> Result<bool, JsError> {
+    let totp = proton_pass_common::totp::totp::TOTP::from_uri_or_secret(&uri)?;
+    Ok(totp.verify(&token, current_time, skew)?)
+}
+
+#[cfg(feature = "qr")]
+#[wasm_bindgen]
+pub fn generate_totp_qr(uri: String) -> Result<js_sys::Uint8Array, JsError> {
+    let totp = proton_pass_common::totp::totp::TOTP::from_uri_or_secret(&uri)?;
+    Ok(utils::vec_to_uint8_array(totp.qr_png()?))
+}
+
 #[wasm_bindgen]
 pub fn get_root_domain(input: String) -> Result<String, JsError> {
     Ok(proton_pass_common::domain::get_root_domain(&input)?)